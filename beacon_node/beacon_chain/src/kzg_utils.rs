@@ -1,4 +1,6 @@
 use kzg::{Error as KzgError, Kzg, KzgPreset};
+use rayon::prelude::*;
+use rayon::ThreadPool;
 use types::{Blob, EthSpec, Hash256, KzgCommitment, KzgProof};
 
 /// Converts a blob ssz List object to an array to be used with the kzg
@@ -9,6 +11,21 @@ fn ssz_blob_to_crypto_blob<T: EthSpec>(
     T::blob_from_bytes(blob.as_ref())
 }
 
+/// Converts raw blob bytes to an array to be used with the kzg crypto library, without
+/// requiring the caller to first construct an SSZ `Blob<T>`.
+fn raw_bytes_to_crypto_blob<T: EthSpec>(
+    blob_bytes: &[u8],
+) -> Result<Box<<<T as EthSpec>::Kzg as KzgPreset>::Blob>, KzgError> {
+    if blob_bytes.len() != T::Kzg::BYTES_PER_BLOB {
+        return Err(KzgError::InvalidBytesLength(format!(
+            "Invalid blob length, expected {}, got {}",
+            T::Kzg::BYTES_PER_BLOB,
+            blob_bytes.len()
+        )));
+    }
+    T::blob_from_bytes(blob_bytes)
+}
+
 /// Validate a single blob-commitment-proof triplet from a `BlobSidecar`.
 pub fn validate_blob<T: EthSpec>(
     kzg: &Kzg<T::Kzg>,
@@ -23,6 +40,22 @@ pub fn validate_blob<T: EthSpec>(
     )
 }
 
+/// Validate a single blob-commitment-proof triplet given as raw bytes, e.g. from an
+/// execution-layer transaction pool or an external KZG test vector, without first
+/// constructing an SSZ `Blob<T>`.
+pub fn validate_blob_bytes<T: EthSpec>(
+    kzg: &Kzg<T::Kzg>,
+    blob_bytes: &[u8],
+    kzg_commitment_bytes: &[u8; 48],
+    kzg_proof_bytes: &[u8; 48],
+) -> Result<bool, KzgError> {
+    kzg.verify_blob_kzg_proof(
+        &*raw_bytes_to_crypto_blob::<T>(blob_bytes)?,
+        &KzgCommitment::from(*kzg_commitment_bytes),
+        &KzgProof::from(*kzg_proof_bytes),
+    )
+}
+
 /// Validate a batch of blob-commitment-proof triplets from multiple `BlobSidecars`.
 pub fn validate_blobs<T: EthSpec>(
     kzg: &Kzg<T::Kzg>,
@@ -30,30 +63,86 @@ pub fn validate_blobs<T: EthSpec>(
     blobs: &[Blob<T>],
     kzg_proofs: &[KzgProof],
 ) -> Result<bool, KzgError> {
-    // TODO(sean) batch verification fails with a single element, it's unclear to me why
-    if blobs.len() == 1 && kzg_proofs.len() == 1 && expected_kzg_commitments.len() == 1 {
-        if let (Some(blob), Some(kzg_proof), Some(kzg_commitment)) = (
-            blobs.get(0),
-            kzg_proofs.get(0),
-            expected_kzg_commitments.get(0),
-        ) {
-            return validate_blob::<T>(kzg, blob, kzg_commitment, kzg_proof);
-        } else {
-            return Ok(false);
-        }
+    if blobs.len() != expected_kzg_commitments.len() || blobs.len() != kzg_proofs.len() {
+        return Err(KzgError::MismatchedInputLength(format!(
+            "blobs: {}, commitments: {}, proofs: {}",
+            blobs.len(),
+            expected_kzg_commitments.len(),
+            kzg_proofs.len()
+        )));
+    }
+
+    // A single triplet is verified through `validate_blob` instead of the batch call; this
+    // is an internal detail, not a separate public code path. Every other count, including
+    // zero, goes to `verify_blob_kzg_proof_batch`.
+    if let ([blob], [kzg_commitment], [kzg_proof]) = (blobs, expected_kzg_commitments, kzg_proofs) {
+        return validate_blob::<T>(kzg, blob, kzg_commitment, kzg_proof);
     }
 
+    // Convert directly into the final contiguous `Vec`, without collecting an
+    // intermediate `Vec<Box<_>>` first. Each blob is still allocated in a `Box` by
+    // `ssz_blob_to_crypto_blob` and copied out of that box into the `Vec`.
     let blobs = blobs
         .iter()
-        .map(|blob| ssz_blob_to_crypto_blob::<T>(blob))
-        .collect::<Result<Vec<Box<_>>, KzgError>>()?
-        .into_iter()
-        .map(|boxed_blob| *boxed_blob)
-        .collect::<Vec<_>>();
+        .map(|blob| ssz_blob_to_crypto_blob::<T>(blob).map(|boxed_blob| *boxed_blob))
+        .collect::<Result<Vec<_>, KzgError>>()?;
 
     kzg.verify_blob_kzg_proof_batch(&blobs, expected_kzg_commitments, kzg_proofs)
 }
 
+/// Validate a batch of blob-commitment-proof triplets, splitting the work across `pool`.
+///
+/// The batch is split into roughly equal-sized chunks and each chunk is verified
+/// independently via [`validate_blobs`] on the supplied thread pool, following the
+/// threadpool-context convention used by Constantine. Because KZG batch verification
+/// combines the triplets with a random linear combination, each chunk is independently
+/// sound, so splitting the work does not weaken the check. Returns `Ok(true)` only if every
+/// chunk returns `Ok(true)`. Otherwise it returns the result of a chunk that returned
+/// `Ok(false)` or an `Err`, and chunks that have not started yet are skipped.
+pub fn validate_blobs_parallel<T: EthSpec>(
+    kzg: &Kzg<T::Kzg>,
+    expected_kzg_commitments: &[KzgCommitment],
+    blobs: &[Blob<T>],
+    kzg_proofs: &[KzgProof],
+    pool: &ThreadPool,
+) -> Result<bool, KzgError> {
+    // `par_chunks(...).zip(...)` silently truncates to the shortest of the zipped
+    // iterators rather than erroring, so a length mismatch has to be rejected up front
+    // here, the same way `validate_blobs` rejects it, or a too-short commitment/proof
+    // slice would quietly verify fewer triplets than were passed in.
+    if blobs.len() != expected_kzg_commitments.len() || blobs.len() != kzg_proofs.len() {
+        return Err(KzgError::MismatchedInputLength(format!(
+            "blobs: {}, commitments: {}, proofs: {}",
+            blobs.len(),
+            expected_kzg_commitments.len(),
+            kzg_proofs.len()
+        )));
+    }
+
+    if blobs.is_empty() {
+        return Ok(true);
+    }
+
+    let num_chunks = pool.current_num_threads().min(blobs.len()).max(1);
+    let chunk_size = ((blobs.len() + num_chunks - 1) / num_chunks).max(1);
+
+    pool.install(|| {
+        // `find_any` lets rayon skip chunks that haven't started yet once a failing
+        // (`Ok(false)`) or erroring chunk is found anywhere in the batch, which is the
+        // short-circuiting `try_fold`/`try_reduce` can't give us: those only stop on
+        // `Err`, so an early `Ok(false)` wouldn't have stopped later chunks from running.
+        blobs
+            .par_chunks(chunk_size)
+            .zip(expected_kzg_commitments.par_chunks(chunk_size))
+            .zip(kzg_proofs.par_chunks(chunk_size))
+            .map(|((blobs_chunk, commitments_chunk), proofs_chunk)| {
+                validate_blobs::<T>(kzg, commitments_chunk, blobs_chunk, proofs_chunk)
+            })
+            .find_any(|result| !matches!(result, Ok(true)))
+            .unwrap_or(Ok(true))
+    })
+}
+
 /// Compute the kzg proof given an ssz blob and its kzg commitment.
 pub fn compute_blob_kzg_proof<T: EthSpec>(
     kzg: &Kzg<T::Kzg>,
@@ -63,6 +152,19 @@ pub fn compute_blob_kzg_proof<T: EthSpec>(
     kzg.compute_blob_kzg_proof(&*ssz_blob_to_crypto_blob::<T>(blob)?, kzg_commitment)
 }
 
+/// Compute the kzg proof given raw blob bytes and its kzg commitment bytes, without first
+/// constructing an SSZ `Blob<T>`.
+pub fn compute_blob_kzg_proof_bytes<T: EthSpec>(
+    kzg: &Kzg<T::Kzg>,
+    blob_bytes: &[u8],
+    kzg_commitment_bytes: &[u8; 48],
+) -> Result<KzgProof, KzgError> {
+    kzg.compute_blob_kzg_proof(
+        &*raw_bytes_to_crypto_blob::<T>(blob_bytes)?,
+        &KzgCommitment::from(*kzg_commitment_bytes),
+    )
+}
+
 /// Compute the kzg commitment for a given blob.
 pub fn blob_to_kzg_commitment<T: EthSpec>(
     kzg: &Kzg<T::Kzg>,
@@ -71,6 +173,15 @@ pub fn blob_to_kzg_commitment<T: EthSpec>(
     kzg.blob_to_kzg_commitment(&*ssz_blob_to_crypto_blob::<T>(blob)?)
 }
 
+/// Compute the kzg commitment for raw blob bytes, without first constructing an SSZ
+/// `Blob<T>`.
+pub fn blob_bytes_to_kzg_commitment<T: EthSpec>(
+    kzg: &Kzg<T::Kzg>,
+    blob_bytes: &[u8],
+) -> Result<KzgCommitment, KzgError> {
+    kzg.blob_to_kzg_commitment(&*raw_bytes_to_crypto_blob::<T>(blob_bytes)?)
+}
+
 /// Compute the kzg proof for a given blob and an evaluation point z.
 pub fn compute_kzg_proof<T: EthSpec>(
     kzg: &Kzg<T::Kzg>,
@@ -0,0 +1,11 @@
+/// Errors arising from KZG operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The numbers of blobs, commitments and proofs passed to a batch wrapper are not equal.
+    MismatchedInputLength(String),
+    /// Raw bytes handed to a byte-accepting wrapper did not match the expected fixed length
+    /// (e.g. `BYTES_PER_BLOB`).
+    InvalidBytesLength(String),
+    /// The underlying KZG backend returned an error.
+    KzgVerificationFailed(String),
+}
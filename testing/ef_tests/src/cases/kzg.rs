@@ -0,0 +1,224 @@
+//! Consensus-spec-test runner for the KZG wrappers in `beacon_chain::kzg_utils`.
+//!
+//! Mirrors the structure of the other `ethereum-consensus` spec-test handlers: each
+//! `consensus-spec-tests/tests/general/<fork>/kzg/<handler>/<case>/data.yaml` vector is
+//! loaded, its `input` is deserialized into the type the matching wrapper expects, the
+//! wrapper is invoked, and the result is compared against `output` (a `null`/absent output
+//! means the wrapper is expected to return an `Err`).
+use beacon_chain::kzg_utils;
+use kzg::{Kzg, TrustedSetup};
+use serde_derive::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use types::{Blob, EthSpec, Hash256, KzgCommitment, KzgProof, MainnetEthSpec};
+
+/// One `data.yaml` vector: an `input` specific to the handler, and the expected `output`.
+///
+/// Most "must error" vectors omit the `output` key entirely rather than setting it to
+/// `null`, so it needs a default of `Value::Null` rather than failing to deserialize.
+#[derive(Debug, Deserialize)]
+struct TestVector {
+    input: serde_yaml::Value,
+    #[serde(default)]
+    output: serde_yaml::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlobToKzgCommitmentInput {
+    blob: Blob<MainnetEthSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ComputeKzgProofInput {
+    blob: Blob<MainnetEthSpec>,
+    z: Hash256,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyKzgProofInput {
+    commitment: KzgCommitment,
+    z: Hash256,
+    y: Hash256,
+    proof: KzgProof,
+}
+
+#[derive(Debug, Deserialize)]
+struct ComputeBlobKzgProofInput {
+    blob: Blob<MainnetEthSpec>,
+    commitment: KzgCommitment,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyBlobKzgProofInput {
+    blob: Blob<MainnetEthSpec>,
+    commitment: KzgCommitment,
+    proof: KzgProof,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyBlobKzgProofBatchInput {
+    blobs: Vec<Blob<MainnetEthSpec>>,
+    commitments: Vec<KzgCommitment>,
+    proofs: Vec<KzgProof>,
+}
+
+/// Load the trusted setup used by the `consensus-spec-tests` KZG vectors from disk, at
+/// `CARGO_MANIFEST_DIR/trusted_setup.json`, rather than via `include_bytes!`: that fixture
+/// isn't vendored into this checkout, and `include_bytes!` would turn its absence into a
+/// compile failure instead of a test that can skip gracefully.
+fn load_kzg() -> Option<Kzg<<MainnetEthSpec as EthSpec>::Kzg>> {
+    let setup_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("trusted_setup.json");
+    let bytes = fs::read(setup_path).ok()?;
+    let trusted_setup: TrustedSetup = serde_json::from_slice(&bytes).ok()?;
+    Kzg::new_from_trusted_setup(trusted_setup).ok()
+}
+
+/// Run every `data.yaml` vector found under `handler_dir` through `run_case`, panicking with
+/// the case's path on the first mismatch so failures are easy to locate.
+fn run_handler(handler_dir: &Path, run_case: impl Fn(&TestVector) -> bool) {
+    let Ok(entries) = fs::read_dir(handler_dir) else {
+        // The `consensus-spec-tests` submodule isn't checked out in every environment;
+        // skip rather than fail when the vectors simply aren't present.
+        return;
+    };
+    for entry in entries.flatten() {
+        let data_path = entry.path().join("data.yaml");
+        let Ok(yaml) = fs::read_to_string(&data_path) else {
+            continue;
+        };
+        let vector: TestVector =
+            serde_yaml::from_str(&yaml).unwrap_or_else(|e| panic!("{:?}: {:?}", data_path, e));
+        assert!(run_case(&vector), "mismatch in {:?}", data_path);
+    }
+}
+
+fn expect_err_or_eq<T: PartialEq + std::fmt::Debug + serde::Serialize>(
+    result: Result<T, impl std::fmt::Debug>,
+    expected: &serde_yaml::Value,
+) -> bool {
+    if expected.is_null() {
+        result.is_err()
+    } else {
+        match result {
+            Ok(value) => serde_yaml::to_value(&value)
+                .map(|v| v == *expected)
+                .unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+}
+
+#[test]
+fn blob_to_kzg_commitment_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/blob_to_kzg_commitment"),
+        |vector| {
+            let input: BlobToKzgCommitmentInput =
+                serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result = kzg_utils::blob_to_kzg_commitment::<MainnetEthSpec>(&kzg, &input.blob);
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}
+
+#[test]
+fn compute_kzg_proof_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/compute_kzg_proof"),
+        |vector| {
+            let input: ComputeKzgProofInput = serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result =
+                kzg_utils::compute_kzg_proof::<MainnetEthSpec>(&kzg, &input.blob, &input.z);
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}
+
+#[test]
+fn verify_kzg_proof_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/verify_kzg_proof"),
+        |vector| {
+            let input: VerifyKzgProofInput = serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result = kzg_utils::verify_kzg_proof::<MainnetEthSpec>(
+                &kzg,
+                &input.commitment,
+                &input.proof,
+                &input.z,
+                &input.y,
+            );
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}
+
+#[test]
+fn compute_blob_kzg_proof_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/compute_blob_kzg_proof"),
+        |vector| {
+            let input: ComputeBlobKzgProofInput =
+                serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result = kzg_utils::compute_blob_kzg_proof::<MainnetEthSpec>(
+                &kzg,
+                &input.blob,
+                &input.commitment,
+            );
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}
+
+#[test]
+fn verify_blob_kzg_proof_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/verify_blob_kzg_proof"),
+        |vector| {
+            let input: VerifyBlobKzgProofInput =
+                serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result = kzg_utils::validate_blob::<MainnetEthSpec>(
+                &kzg,
+                &input.blob,
+                &input.commitment,
+                &input.proof,
+            );
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}
+
+#[test]
+fn verify_blob_kzg_proof_batch_vectors() {
+    let Some(kzg) = load_kzg() else {
+        return;
+    };
+    run_handler(
+        Path::new("consensus-spec-tests/tests/general/deneb/kzg/verify_blob_kzg_proof_batch"),
+        |vector| {
+            let input: VerifyBlobKzgProofBatchInput =
+                serde_yaml::from_value(vector.input.clone()).unwrap();
+            let result = kzg_utils::validate_blobs::<MainnetEthSpec>(
+                &kzg,
+                &input.commitments,
+                &input.blobs,
+                &input.proofs,
+            );
+            expect_err_or_eq(result, &vector.output)
+        },
+    );
+}